@@ -0,0 +1,3 @@
+mod traits;
+
+pub use traits::{Arch, Registers};
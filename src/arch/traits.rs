@@ -10,8 +10,6 @@ use crate::internal::BeBytes;
 /// e.g: for ARM:
 /// github.com/bminor/binutils-gdb/blob/master/gdb/features/arm/arm-core.xml
 // TODO: add way to de/serialize arbitrary "missing"/"uncollected" registers.
-// TODO: add (optional?) trait methods for reading/writing specific register
-// (via it's GDB index)
 pub trait Registers: Default {
     /// Serialize `self` into a GDB register bytestream.
     ///
@@ -20,6 +18,43 @@ pub trait Registers: Default {
 
     /// Deserialize a GDB register bytestream into `self`.
     fn gdb_deserialize(&mut self, bytes: &[u8]) -> Result<(), ()>;
+
+    /// Serialize a single register, identified by its GDB index (i.e: the
+    /// same ordering used by [`gdb_serialize`](Registers::gdb_serialize)),
+    /// into a GDB register bytestream.
+    ///
+    /// Returns `false` if `reg_idx` is out of range.
+    ///
+    /// Implementing this method is optional, and is only required to
+    /// support GDB's single-register `p` packet. When unimplemented, `p`
+    /// requests are serviced by serializing the entire register file via
+    /// `gdb_serialize` and slicing out the relevant bytes, which is
+    /// correct but forgoes the opportunity to avoid collecting registers
+    /// the target hasn't been asked for.
+    ///
+    /// The default implementation simply returns `false`.
+    fn read_register(&self, reg_idx: usize, write_byte: impl FnMut(Option<u8>)) -> bool {
+        let _ = (reg_idx, write_byte);
+        false
+    }
+
+    /// Deserialize a single register, identified by its GDB index (i.e: the
+    /// same ordering used by [`gdb_serialize`](Registers::gdb_serialize)),
+    /// from a GDB register bytestream.
+    ///
+    /// Returns `Err(())` if `reg_idx` is out of range, or `bytes` doesn't
+    /// match the register's expected width.
+    ///
+    /// Implementing this method is optional, and is only required to
+    /// support GDB's single-register `P` packet. See
+    /// [`read_register`](Registers::read_register) for details.
+    ///
+    /// The default implementation simply returns `Err(())`.
+    #[allow(clippy::result_unit_err)]
+    fn write_register(&mut self, reg_idx: usize, bytes: &[u8]) -> Result<(), ()> {
+        let _ = (reg_idx, bytes);
+        Err(())
+    }
 }
 
 /// Encodes architecture-specific information, such as pointer size, register
@@ -31,13 +66,15 @@ pub trait Arch: Eq + PartialEq {
     /// The architecture's register file
     type Registers: Registers;
 
-    /// (optional) Return the platform's `features.xml` file.
+    /// (optional) Return the contents of the named target description XML
+    /// annex, served to GDB over `qXfer:features:read:<annex>:...`.
     ///
     /// Implementing this method enables `gdb` to automatically detect the
     /// target's architecture, saving the hassle of having to run `set
     /// architecture <arch>` when starting a debugging session.
     ///
-    /// These descriptions can be quite succinct. For example, the target
+    /// GDB always starts by requesting the `target.xml` annex. These
+    /// descriptions can be quite succinct. For example, the target
     /// description for an `armv4t` platform can be as simple as:
     ///
     /// ```
@@ -45,9 +82,20 @@ pub trait Arch: Eq + PartialEq {
     /// # ;
     /// ```
     ///
+    /// Larger descriptions are free to split register groups into their own
+    /// annexes and pull them in via `<xi:include>`, e.g: `target.xml`
+    /// including `arm-core.xml` and `arm-vfp.xml`. `gdbstub` takes care of
+    /// serving whichever annex GDB asks for, chunked to fit the negotiated
+    /// packet size; implementors just need to return the full contents of
+    /// `annex`.
+    ///
+    /// Returns `None` if `annex` isn't a target description served by this
+    /// architecture.
+    ///
     /// See the [GDB docs](https://sourceware.org/gdb/current/onlinedocs/gdb/Target-Description-Format.html)
     /// for details on the target description XML format.
-    fn target_description_xml() -> Option<&'static str> {
+    fn target_description_xml(annex: &str) -> Option<&'static str> {
+        let _ = annex;
         None
     }
 }
@@ -0,0 +1,85 @@
+//! Base operations for resuming execution and accessing a target's state.
+//!
+//! Every target implements exactly one of [`singlethread::SingleThread`] or
+//! [`multithread::MultiThread`], depending on whether it exposes a single
+//! execution context to GDB, or multiple ones (e.g: SMP emulators, RTOS
+//! targets with multiple tasks, ...).
+
+use crate::target::ext::breakpoints::WatchKind;
+
+pub mod multithread;
+pub mod singlethread;
+
+/// How the target should resume execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeAction {
+    /// Continue execution.
+    Continue,
+    /// Step execution by a single instruction.
+    Step,
+}
+
+/// The reason execution halted, as reported back to GDB via a stop-reply
+/// packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason<U> {
+    /// Exited with provided exit code.
+    Exited(u8),
+    /// Terminated by a signal.
+    Signalled(u8),
+    /// Hit a software breakpoint (e.g: due to a `trap` instruction) while
+    /// executing at the given address.
+    SwBreak(U),
+    /// Hit a hardware breakpoint set via
+    /// [`HwBreakpoint::add_hw_breakpoint`](crate::target::ext::breakpoints::HwBreakpoint::add_hw_breakpoint).
+    HwBreak,
+    /// Hit a hardware watchpoint set via
+    /// [`HwWatchpoint`](crate::target::ext::breakpoints::HwWatchpoint).
+    HwWatch {
+        /// The kind of access that triggered the watchpoint.
+        kind: WatchKind,
+        /// The address of the watched memory that was accessed.
+        addr: U,
+    },
+    /// Execution halted due to a received Ctrl-C.
+    GdbInterrupt,
+}
+
+/// A thread identifier, as used by the GDB remote protocol (`Hg`/`Hc`,
+/// `qfThreadInfo`/`qsThreadInfo`, the `thread-id` prefix on `p`/`g`/`m`
+/// packets, ...).
+///
+/// GDB reserves the values `0` ("pick any thread") and `-1` ("all threads")
+/// for out-of-band use, so `Tid` itself is restricted to the remaining,
+/// strictly positive range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tid(core::num::NonZeroUsize);
+
+impl Tid {
+    /// Create a new `Tid` from a raw, nonzero thread ID.
+    ///
+    /// Returns `None` if `raw` is `0`.
+    pub fn new(raw: usize) -> Option<Tid> {
+        core::num::NonZeroUsize::new(raw).map(Tid)
+    }
+
+    /// Returns the raw thread ID.
+    pub fn raw(self) -> usize {
+        self.0.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tid_rejects_zero() {
+        assert_eq!(Tid::new(0), None);
+    }
+
+    #[test]
+    fn tid_roundtrips_raw_value() {
+        assert_eq!(Tid::new(42).unwrap().raw(), 42);
+    }
+}
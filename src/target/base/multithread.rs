@@ -0,0 +1,156 @@
+use crate::arch::Arch;
+use crate::target::base::*;
+use crate::target::Target;
+
+/// Per-thread resume instructions, as used by [`MultiThread::resume`].
+///
+/// GDB's `vCont` packet specifies a [`ResumeAction`] for a set of threads,
+/// plus a default action applied to any thread not explicitly mentioned.
+#[derive(Debug, Clone, Copy)]
+pub struct ResumeActions<'a> {
+    actions: &'a [(Tid, ResumeAction)],
+    default_action: ResumeAction,
+}
+
+impl<'a> ResumeActions<'a> {
+    /// Create a new set of resume actions.
+    ///
+    /// `default_action` is applied to any thread not present in `actions`.
+    pub fn new(actions: &'a [(Tid, ResumeAction)], default_action: ResumeAction) -> Self {
+        ResumeActions {
+            actions,
+            default_action,
+        }
+    }
+
+    /// Look up the [`ResumeAction`] that should be applied to `tid`.
+    pub fn action_for(&self, tid: Tid) -> ResumeAction {
+        self.actions
+            .iter()
+            .find(|(t, _)| *t == tid)
+            .map(|(_, action)| *action)
+            .unwrap_or(self.default_action)
+    }
+}
+
+/// The reason execution halted, as reported back to GDB via a stop-reply
+/// packet, alongside the [`Tid`] of the thread that caused the stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreadStopReason<U> {
+    /// The thread that caused the stop.
+    ///
+    /// `None` for stop reasons that aren't associated with any particular
+    /// thread (e.g: the whole process exiting).
+    pub tid: Option<Tid>,
+    /// Why execution halted.
+    pub reason: StopReason<U>,
+}
+
+/// Core operations for multi threaded (e.g: SMP, or RTOS) targets.
+#[allow(clippy::type_complexity)]
+pub trait MultiThread: Target {
+    /// Resume execution on the target.
+    ///
+    /// `actions` specifies how each thread should be resumed (i.e:
+    /// single-step vs. full continue), with threads not explicitly listed
+    /// resumed according to `actions`'s default action.
+    ///
+    /// The `check_gdb_interrupt` callback can be invoked to check if GDB sent
+    /// an Interrupt packet (i.e: the user pressed Ctrl-C). It's recommended to
+    /// invoke this callback every-so-often while the system is running (e.g:
+    /// every X cycles/milliseconds).
+    fn resume(
+        &mut self,
+        actions: ResumeActions<'_>,
+        check_gdb_interrupt: &mut dyn FnMut() -> bool,
+    ) -> Result<ThreadStopReason<<Self::Arch as Arch>::Usize>, Self::Error>;
+
+    /// Read the registers of the specified thread.
+    fn read_registers(
+        &mut self,
+        regs: &mut <Self::Arch as Arch>::Registers,
+        tid: Tid,
+    ) -> Result<(), Self::Error>;
+
+    /// Write the registers of the specified thread.
+    fn write_registers(
+        &mut self,
+        regs: &<Self::Arch as Arch>::Registers,
+        tid: Tid,
+    ) -> Result<(), Self::Error>;
+
+    /// Read bytes from the specified address range, as observed by the
+    /// specified thread.
+    ///
+    /// See [`SingleThread::read_addrs`](super::singlethread::SingleThread::read_addrs)
+    /// for details on how to handle non-fatal invalid memory reads.
+    fn read_addrs(
+        &mut self,
+        start_addr: <Self::Arch as Arch>::Usize,
+        data: &mut [u8],
+        tid: Tid,
+    ) -> Result<bool, Self::Error>;
+
+    /// Write bytes to the specified address range, as observed by the
+    /// specified thread.
+    ///
+    /// See [`SingleThread::write_addrs`](super::singlethread::SingleThread::write_addrs)
+    /// for details on how to handle non-fatal invalid memory writes.
+    fn write_addrs(
+        &mut self,
+        start_addr: <Self::Arch as Arch>::Usize,
+        data: &[u8],
+        tid: Tid,
+    ) -> Result<bool, Self::Error>;
+
+    /// Invoke `register_thread` for every currently active thread.
+    ///
+    /// Used to answer GDB's `qfThreadInfo`/`qsThreadInfo` queries.
+    fn list_active_threads(
+        &mut self,
+        register_thread: &mut dyn FnMut(Tid),
+    ) -> Result<(), Self::Error>;
+
+    /// Return the thread that should be considered "selected" (i.e: the
+    /// thread GDB's `Hg`/`Hc` packets should target by default).
+    ///
+    /// Defaults to `None`, in which case `gdbstub` picks an arbitrary active
+    /// thread.
+    fn selected_thread(&mut self) -> Result<Option<Tid>, Self::Error> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn action_for_explicit_entry() {
+        let t1 = Tid::new(1).unwrap();
+        let t2 = Tid::new(2).unwrap();
+        let actions = ResumeActions::new(&[(t1, ResumeAction::Step)], ResumeAction::Continue);
+
+        assert_eq!(actions.action_for(t1), ResumeAction::Step);
+        assert_eq!(actions.action_for(t2), ResumeAction::Continue);
+    }
+
+    #[test]
+    fn action_for_falls_back_to_default_with_no_entries() {
+        let t1 = Tid::new(1).unwrap();
+        let actions = ResumeActions::new(&[], ResumeAction::Continue);
+
+        assert_eq!(actions.action_for(t1), ResumeAction::Continue);
+    }
+
+    #[test]
+    fn action_for_picks_first_match_among_duplicates() {
+        let t1 = Tid::new(1).unwrap();
+        let actions = ResumeActions::new(
+            &[(t1, ResumeAction::Step), (t1, ResumeAction::Continue)],
+            ResumeAction::Continue,
+        );
+
+        assert_eq!(actions.action_for(t1), ResumeAction::Step);
+    }
+}
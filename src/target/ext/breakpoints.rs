@@ -0,0 +1,66 @@
+//! Target extension traits for managing breakpoints and watchpoints,
+//! surfaced to GDB through the `z`/`Z` packets.
+
+/// The kind of memory access a hardware watchpoint should trap on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchKind {
+    /// Trap on writes.
+    Write,
+    /// Trap on reads.
+    Read,
+    /// Trap on both reads and writes.
+    ReadWrite,
+}
+
+/// Target extension: manage software breakpoints.
+///
+/// Without this extension, GDB falls back to managing software breakpoints
+/// itself, by patching memory directly through `read_addrs`/`write_addrs`.
+/// Implement it when the target can do better than that (e.g: by trapping
+/// at the ISA level), or when memory isn't just a flat, writable image.
+pub trait Breakpoints<U, E> {
+    /// Add a software breakpoint at `addr`.
+    ///
+    /// Returns `Ok(false)` if the breakpoint could not be set.
+    fn add_sw_breakpoint(&mut self, addr: U) -> Result<bool, E>;
+
+    /// Remove the software breakpoint previously set at `addr`.
+    ///
+    /// Returns `Ok(false)` if there was no breakpoint at `addr`.
+    fn remove_sw_breakpoint(&mut self, addr: U) -> Result<bool, E>;
+}
+
+/// Target extension: manage hardware breakpoints.
+///
+/// Implement this independently of [`Breakpoints`] when the target has
+/// dedicated hardware breakpoint registers (e.g: XIP flash with no
+/// writable code memory), so it isn't forced to also stub out software
+/// breakpoint support just to opt in.
+pub trait HwBreakpoint<U, E> {
+    /// Add a hardware breakpoint at `addr`.
+    ///
+    /// Returns `Ok(false)` if the breakpoint could not be set (e.g: the
+    /// target ran out of hardware breakpoint slots).
+    fn add_hw_breakpoint(&mut self, addr: U) -> Result<bool, E>;
+
+    /// Remove the hardware breakpoint previously set at `addr`.
+    ///
+    /// Returns `Ok(false)` if there was no breakpoint at `addr`.
+    fn remove_hw_breakpoint(&mut self, addr: U) -> Result<bool, E>;
+}
+
+/// Target extension: manage hardware watchpoints.
+pub trait HwWatchpoint<U, E> {
+    /// Add a hardware watchpoint of the given `kind`, covering `len` bytes
+    /// starting at `addr`.
+    ///
+    /// Returns `Ok(false)` if the watchpoint could not be set (e.g: the
+    /// target ran out of hardware watchpoint slots).
+    fn add_hw_watchpoint(&mut self, addr: U, len: U, kind: WatchKind) -> Result<bool, E>;
+
+    /// Remove the hardware watchpoint of the given `kind` previously set at
+    /// `addr`.
+    ///
+    /// Returns `Ok(false)` if there was no matching watchpoint.
+    fn remove_hw_watchpoint(&mut self, addr: U, len: U, kind: WatchKind) -> Result<bool, E>;
+}
@@ -0,0 +1,11 @@
+//! Optional target capabilities.
+//!
+//! Unlike the traits under [`base`](crate::target::base), which every target
+//! must implement, the traits in this module are opt-in: a target exposes
+//! one by overriding the relevant accessor method on
+//! [`Target`](crate::target::Target) (e.g:
+//! [`Target::breakpoints`](crate::target::Target::breakpoints)), and
+//! `gdbstub` enables the corresponding GDB packets only when that accessor
+//! returns `Some`.
+
+pub mod breakpoints;
@@ -0,0 +1,90 @@
+//! The `Target` trait, and its various associated extension traits.
+//!
+//! Implementing [`Target`] (and whichever of the `base` traits suit your
+//! target) is the primary way to hook a project up to `gdbstub`.
+
+use crate::arch::Arch;
+use crate::target::ext::breakpoints::{Breakpoints, HwBreakpoint, HwWatchpoint};
+
+pub mod base;
+pub mod ext;
+
+/// The core trait implemented by a debugging target.
+///
+/// `Target` only encodes a target's architecture and fatal error type. The
+/// actual debugging operations (resuming execution, reading/writing
+/// registers and memory, ...) are implemented via the traits under
+/// [`base`], which all take `Target` as a supertrait. Optional capabilities
+/// (breakpoints, watchpoints, ...) live under [`ext`], and are discovered at
+/// runtime through accessor methods on `Target` itself, such as
+/// [`Target::breakpoints`].
+pub trait Target {
+    /// The target's architecture.
+    type Arch: Arch;
+
+    /// A target-specific fatal error.
+    ///
+    /// This error is only for _fatal_ errors, such as a disconnected
+    /// hardware debug probe. Non-fatal errors (e.g: an invalid memory
+    /// access) should be reported through the relevant method's return
+    /// value instead.
+    type Error;
+
+    /// Support for managing software breakpoints (the `z0`/`Z0` packets).
+    ///
+    /// Returning `None` (the default) tells GDB to fall back to managing
+    /// software breakpoints itself, by patching memory directly.
+    fn breakpoints(
+        &mut self,
+    ) -> Option<&mut dyn Breakpoints<<Self::Arch as Arch>::Usize, Self::Error>> {
+        None
+    }
+
+    /// Support for managing hardware breakpoints (the `z1`/`Z1` packets).
+    ///
+    /// Returning `None` (the default) tells GDB that the target doesn't
+    /// support hardware breakpoints.
+    fn hw_breakpoint(
+        &mut self,
+    ) -> Option<&mut dyn HwBreakpoint<<Self::Arch as Arch>::Usize, Self::Error>> {
+        None
+    }
+
+    /// Support for managing hardware watchpoints (the `z2`/`Z2`, `z3`/`Z3`,
+    /// and `z4`/`Z4` packets).
+    ///
+    /// Returning `None` (the default) tells GDB that the target doesn't
+    /// support hardware watchpoints.
+    fn hw_watchpoint(
+        &mut self,
+    ) -> Option<&mut dyn HwWatchpoint<<Self::Arch as Arch>::Usize, Self::Error>> {
+        None
+    }
+
+    /// (optional) Return the target's `memory-map` XML document, served
+    /// over `qXfer:memory-map:read`.
+    ///
+    /// Describing the target's memory regions (RAM, ROM, flash, along with
+    /// flash block sizes) lets GDB drive its flash-programming commands,
+    /// and keeps it from probing addresses outside any known region, which
+    /// matters most for bare-metal and MCU targets. For example:
+    ///
+    /// ```
+    /// r#"<memory-map>
+    ///      <memory type="ram" start="0x20000000" length="0x10000"/>
+    ///      <memory type="flash" start="0x8000000" length="0x40000">
+    ///        <property name="blocksize">0x400</property>
+    ///      </memory>
+    ///    </memory-map>"#
+    /// # ;
+    /// ```
+    ///
+    /// Returning `None` (the default) means the target has no memory map
+    /// to report.
+    ///
+    /// See the [GDB docs](https://sourceware.org/gdb/current/onlinedocs/gdb/Memory-Map-Format.html)
+    /// for details on the memory map XML format.
+    fn memory_map_xml(&self) -> Option<&str> {
+        None
+    }
+}